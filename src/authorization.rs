@@ -0,0 +1,66 @@
+//! Notification authorization handling.
+//!
+//! Modern macOS routes delivery through `UNUserNotificationCenter`, which
+//! requires the user to grant permission before any banner, sound or badge
+//! can be shown. Call [`request_authorization`] once at startup, and check
+//! [`get_authorization_status`] before delivering if you want to avoid a
+//! silently-dropped notification.
+
+/// Whether the user has granted notification permission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorizationStatus {
+    /// The user has not yet been asked.
+    NotDetermined,
+    /// The user denied permission.
+    Denied,
+    /// The user granted permission.
+    Authorized,
+    /// The application is authorized to post notifications provisionally,
+    /// without interrupting the user, until the user acts on one.
+    Provisional,
+}
+
+impl AuthorizationStatus {
+    fn from_raw(raw: i64) -> Self {
+        match raw {
+            1 => AuthorizationStatus::Denied,
+            2 => AuthorizationStatus::Authorized,
+            3 => AuthorizationStatus::Provisional,
+            _ => AuthorizationStatus::NotDetermined,
+        }
+    }
+}
+
+#[link(name = "notify")]
+extern "C" {
+    fn requestAuthorization(alerts: bool, sounds: bool, badges: bool) -> bool;
+    fn getAuthorizationStatus() -> i64;
+}
+
+/// Ask the user to grant notification permission for the given options.
+///
+/// This blocks the calling thread until the user has responded to the
+/// system prompt (or immediately, if a decision was already made), and
+/// returns whether permission was granted.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use mac_notification_sys::*;
+/// let granted = request_authorization(true, true, true);
+/// ```
+pub fn request_authorization(alerts: bool, sounds: bool, badges: bool) -> bool {
+    unsafe { requestAuthorization(alerts, sounds, badges) }
+}
+
+/// Get the current notification authorization status for this application.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use mac_notification_sys::*;
+/// let status = get_authorization_status();
+/// ```
+pub fn get_authorization_status() -> AuthorizationStatus {
+    AuthorizationStatus::from_raw(unsafe { getAuthorizationStatus() })
+}
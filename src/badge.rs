@@ -0,0 +1,51 @@
+//! Dock badge handling, independent of any delivered notification.
+
+use objc_foundation::{INSString, NSString};
+use objc_id::Id;
+use std::ops::Deref;
+
+#[link(name = "notify")]
+extern "C" {
+    fn setBadgeLabel(label: *mut NSString);
+    fn getBadgeLabel() -> *mut NSString;
+}
+
+/// Set the application's dock badge to `count`, or clear it when `None`.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use mac_notification_sys::*;
+/// set_badge(Some(3));
+/// set_badge(None);
+/// ```
+pub fn set_badge(count: Option<u32>) {
+    let label = count.map(|count| count.to_string());
+    unsafe {
+        setBadgeLabel(match &label {
+            Some(label) => Id::autorelease(NSString::from_str(label)),
+            None => std::ptr::null_mut(),
+        })
+    }
+}
+
+/// Read the application's current dock badge, if one is set.
+///
+/// Returns `None` if the badge is unset or isn't a plain number.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use mac_notification_sys::*;
+/// let count = get_badge();
+/// ```
+pub fn get_badge() -> Option<u32> {
+    let label = unsafe { getBadgeLabel() };
+    if label.is_null() {
+        return None;
+    }
+    // `dockTile.badgeLabel` returns an autoreleased (+0) string, not a
+    // retained one, so this must retain rather than assume ownership.
+    let label = unsafe { Id::from_ptr(label) };
+    label.deref().as_str().parse().ok()
+}
@@ -0,0 +1,132 @@
+//! Non-blocking delivery with a per-notification callback.
+//!
+//! [`crate::send_notification`] only offers a synchronous wait via
+//! [`NotificationOptions::delivery_date`], which freezes the calling thread
+//! until the user interacts. [`deliver_with_callback`] instead returns
+//! immediately and invokes the callback exactly once the user clicks,
+//! replies to, or dismisses it, so GUI/async applications can keep several
+//! notifications outstanding at once without blocking on any one of them.
+//!
+//! The callback fires on whatever thread is running the `UNUserNotificationCenter`
+//! delegate callbacks — in practice, the host application's own `NSApplication`
+//! run loop — so a callback will never arrive in a process that never drives one.
+
+use crate::data::{NotificationHandle, NotificationOptions, NotificationResponse, NotificationSource};
+use crate::error::Error;
+use crate::opt_nsstring;
+use objc::runtime::Object;
+use objc_foundation::{INSString, NSString};
+use objc_id::Id;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::Mutex;
+
+type Callback = Box<dyn FnOnce(NotificationResponse) + Send>;
+
+lazy_static::lazy_static! {
+    static ref CALLBACKS: Mutex<HashMap<String, Callback>> = Mutex::new(HashMap::new());
+}
+
+#[link(name = "notify")]
+extern "C" {
+    fn sendNotificationAsync(
+        title: *mut NSString,
+        subtitle: *mut NSString,
+        message: *mut NSString,
+        options: *mut Object,
+    );
+
+    fn removeCallbackNotification(identifier: *mut NSString);
+}
+
+fn deliver_async(title: &str, subtitle: Option<&str>, message: &str, options: &NotificationOptions) {
+    let dictionary = options.to_dictionary();
+    unsafe {
+        sendNotificationAsync(
+            Id::autorelease(NSString::from_str(title)),
+            opt_nsstring(subtitle),
+            Id::autorelease(NSString::from_str(message)),
+            Id::autorelease(dictionary),
+        );
+    }
+}
+
+/// Remove a notification delivered through [`deliver_with_callback`], whether
+/// it's still pending (scheduled) or already showing.
+pub(crate) fn remove_callback_notification(identifier: &str) {
+    unsafe { removeCallbackNotification(Id::autorelease(NSString::from_str(identifier))) }
+}
+
+/// Re-deliver a notification under the same identifier through
+/// `UNUserNotificationCenter`, used by [`NotificationHandle::update`] for
+/// handles returned by [`deliver_with_callback`].
+pub(crate) fn redeliver_callback(
+    title: &str,
+    subtitle: Option<&str>,
+    message: &str,
+    options: &NotificationOptions,
+) {
+    deliver_async(title, subtitle, message, options)
+}
+
+/// Deliver a notification without blocking the calling thread.
+///
+/// `callback` is invoked exactly once, with the user's response, when the
+/// notification is clicked, replied to, or dismissed.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use mac_notification_sys::*;
+/// let _ = deliver_with_callback(
+///     "Title",
+///     None,
+///     "Message",
+///     &NotificationOptions::new(),
+///     |response| println!("{:?}", response),
+/// );
+/// ```
+pub fn deliver_with_callback<F>(
+    title: &str,
+    subtitle: Option<&str>,
+    message: &str,
+    options: &NotificationOptions,
+    callback: F,
+) -> Result<NotificationHandle, Error>
+where
+    F: FnOnce(NotificationResponse) + Send + 'static,
+{
+    let identifier = options.identifier_or_new();
+    let mut options = options.clone();
+    options.identifier(&identifier);
+
+    CALLBACKS
+        .lock()
+        .unwrap()
+        .insert(identifier.clone(), Box::new(callback));
+
+    deliver_async(title, subtitle, message, &options);
+
+    Ok(NotificationHandle::new(
+        identifier,
+        title.to_owned(),
+        subtitle.map(str::to_owned),
+        message.to_owned(),
+        NotificationSource::Callback,
+    ))
+}
+
+/// Called from `objc/notify.m` once a notification delivered through
+/// [`deliver_with_callback`] is clicked, replied to, or dismissed.
+#[no_mangle]
+extern "C" fn mac_notification_sys_handle_callback(identifier: *const c_char, response: *mut Object) {
+    let identifier = unsafe { CStr::from_ptr(identifier) }
+        .to_string_lossy()
+        .into_owned();
+    let callback = CALLBACKS.lock().unwrap().remove(&identifier);
+    if let Some(callback) = callback {
+        let response = unsafe { Id::from_retained_ptr(response) };
+        callback(NotificationResponse::from_dictionary(response));
+    }
+}
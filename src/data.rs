@@ -1,11 +1,13 @@
 //! Custom structs and enums for mac-notification-sys.
 
+use crate::dictionary::{self, DictionaryBuilder};
 use crate::utilities::check_sound;
-use objc_foundation::{INSDictionary, INSString, NSDictionary, NSString};
+use objc::runtime::Object;
 use objc_id::Id;
-use std::ops::Deref;
+use uuid::Uuid;
 
 /// Possible actions accessible through the main button of the notification
+#[derive(Clone)]
 pub enum MainButton<'a> {
     /// Display a single action with the given name
     ///
@@ -27,16 +29,66 @@ pub enum MainButton<'a> {
     DropdownActions(&'a str, &'a [&'a str]),
     /// Display a text input field with the given placeholder
     ///
+    /// `send_button_title` customizes the label of the submit button
+    /// (defaults to "Send"), and `default_text` pre-fills the field.
+    ///
+    /// `default_text` only takes effect via [`crate::send_notification`]/
+    /// [`crate::deliver`] (the legacy `NSUserNotification` path, which sets
+    /// it through a private `_responseString` key); [`crate::deliver_with_callback`]
+    /// posts through `UNUserNotificationCenter`, which has no API for
+    /// pre-filling a `UNTextInputNotificationAction`'s field, so it's
+    /// silently dropped there.
+    ///
     /// # Example:
     ///
     /// ```no_run
     /// # use mac_notification_sys::*;
-    /// let _ = MainButton::Response("Enter some text...");
+    /// let _ = MainButton::Response {
+    ///     placeholder: "Enter some text...",
+    ///     send_button_title: Some("Reply"),
+    ///     default_text: None,
+    /// };
     /// ```
-    Response(&'a str),
+    Response {
+        /// Placeholder text shown in the empty input field.
+        placeholder: &'a str,
+        /// Label of the submit button, defaulting to "Send" when `None`.
+        send_button_title: Option<&'a str>,
+        /// Text to pre-fill the input field with.
+        default_text: Option<&'a str>,
+    },
+}
+
+/// How urgently a notification should interrupt the user.
+///
+/// Mirrors macOS 12+'s `UNNotificationInterruptionLevel`. Anything above
+/// `Active` can break through Focus/Do Not Disturb, which is useful for
+/// alarms or security alerts that must not be silently swallowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptionLevel {
+    /// Added to the notification list without lighting up the screen.
+    Passive,
+    /// The default: shown immediately, but respects Focus/Do Not Disturb.
+    Active,
+    /// Shown immediately and can break through Focus, but not silenced DND.
+    TimeSensitive,
+    /// Always shown and played, even when the screen is locked or DND is on.
+    Critical,
+}
+
+impl InterruptionLevel {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            InterruptionLevel::Passive => "passive",
+            InterruptionLevel::Active => "active",
+            InterruptionLevel::TimeSensitive => "timeSensitive",
+            InterruptionLevel::Critical => "critical",
+        }
+    }
 }
 
 /// Options to further customize the notification
+#[derive(Clone)]
 pub struct NotificationOptions<'a> {
     pub(crate) main_button: Option<MainButton<'a>>,
     pub(crate) close_button: Option<&'a str>,
@@ -45,6 +97,9 @@ pub struct NotificationOptions<'a> {
     pub(crate) group_id: Option<&'a str>,
     pub(crate) delivery_date: Option<(f64, bool)>,
     pub(crate) sound: Option<&'a str>,
+    pub(crate) identifier: Option<String>,
+    pub(crate) badge: Option<u32>,
+    pub(crate) interruption_level: Option<InterruptionLevel>,
 }
 
 impl<'a> NotificationOptions<'a> {
@@ -58,6 +113,9 @@ impl<'a> NotificationOptions<'a> {
             group_id: None,
             delivery_date: None,
             sound: None,
+            identifier: None,
+            badge: None,
+            interruption_level: None,
         }
     }
 
@@ -165,58 +223,119 @@ impl<'a> NotificationOptions<'a> {
         self
     }
 
+    /// Set the dock badge count to display when the notification is delivered.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use mac_notification_sys::*;
+    /// let _ = NotificationOptions::new().badge(3);
+    /// ```
+    pub fn badge(&mut self, badge: u32) -> &mut Self {
+        self.badge = Some(badge);
+        self
+    }
+
+    /// Set how urgently the notification should interrupt the user.
+    ///
+    /// Only takes effect via [`crate::deliver_with_callback`]. The legacy
+    /// `NSUserNotification` path used by [`crate::send_notification`]/
+    /// [`crate::deliver`] has no interruption-level concept at all, so this
+    /// is silently ignored there — route the notification through
+    /// `deliver_with_callback` if breaking through Focus/Do Not Disturb
+    /// matters.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use mac_notification_sys::*;
+    /// let _ = NotificationOptions::new().interruption_level(InterruptionLevel::TimeSensitive);
+    /// ```
+    pub fn interruption_level(&mut self, interruption_level: InterruptionLevel) -> &mut Self {
+        self.interruption_level = Some(interruption_level);
+        self
+    }
+
+    /// Set the identifier the notification is delivered under.
+    ///
+    /// Notifications sharing an identifier replace one another in place
+    /// instead of stacking, which is how [`NotificationHandle::update`]
+    /// replaces a delivered notification.
+    pub fn identifier(&mut self, identifier: &str) -> &mut Self {
+        self.identifier = Some(identifier.to_owned());
+        self
+    }
+
+    /// Return the identifier the notification will be delivered under.
+    ///
+    /// Falls back to `group_id` when set (so notifications in the same group
+    /// share an identifier and replace one another), and only generates a
+    /// fresh UUID when neither is set.
+    pub(crate) fn identifier_or_new(&self) -> String {
+        self.identifier
+            .clone()
+            .or_else(|| self.group_id.map(str::to_owned))
+            .unwrap_or_else(|| Uuid::new_v4().to_string())
+    }
+
     /// Convert the NotificationOptions into an Objective C NSDictionary
-    pub(crate) fn to_dictionary(&self) -> Id<NSDictionary<NSString, NSString>> {
-        // TODO: If possible, find a way to simplify this so I don't have to manually convert struct to NSDictionary
-        let keys = &[
-            &*NSString::from_str("mainButtonLabel"),
-            &*NSString::from_str("actions"),
-            &*NSString::from_str("closeButtonLabel"),
-            &*NSString::from_str("appIcon"),
-            &*NSString::from_str("contentImage"),
-            &*NSString::from_str("groupID"),
-            &*NSString::from_str("response"),
-            &*NSString::from_str("deliveryDate"),
-            &*NSString::from_str("synchronous"),
-            &*NSString::from_str("sound"),
-        ];
-        let (main_button_label, actions, is_response): (&str, &[&str], bool) =
-            match &self.main_button {
-                Some(main_button) => match main_button {
-                    MainButton::SingleAction(main_button_label) => (main_button_label, &[], false),
-                    MainButton::DropdownActions(main_button_label, actions) => {
-                        (main_button_label, actions, false)
-                    }
-                    MainButton::Response(response) => (response, &[], true),
-                },
-                None => ("", &[], false),
-            };
+    pub(crate) fn to_dictionary(&self) -> Id<Object> {
+        let (main_button_label, actions, is_response, send_button_title, default_text): (
+            &str,
+            &[&str],
+            bool,
+            Option<&str>,
+            Option<&str>,
+        ) = match &self.main_button {
+            Some(main_button) => match main_button {
+                MainButton::SingleAction(main_button_label) => {
+                    (main_button_label, &[], false, None, None)
+                }
+                MainButton::DropdownActions(main_button_label, actions) => {
+                    (main_button_label, actions, false, None, None)
+                }
+                MainButton::Response {
+                    placeholder,
+                    send_button_title,
+                    default_text,
+                } => (placeholder, &[], true, *send_button_title, *default_text),
+            },
+            None => ("", &[], false, None, None),
+        };
 
-        let vals = vec![
-            NSString::from_str(main_button_label),
-            // TODO: Find a way to support NSArray as a NSDictionary Value rather than JUST NSString so I don't have to convert array to string and back
-            NSString::from_str(&actions.join(",")),
-            NSString::from_str(self.close_button.unwrap_or("")),
-            NSString::from_str(self.app_icon.unwrap_or("")),
-            NSString::from_str(self.content_image.unwrap_or("")),
-            NSString::from_str(self.group_id.unwrap_or_default()),
-            // TODO: Same as above, if NSDictionary could support multiple types, this could be a boolean
-            NSString::from_str(if is_response { "yes" } else { "" }),
-            NSString::from_str(&match self.delivery_date {
-                Some((delivery_date, _)) => delivery_date.to_string(),
-                _ => String::new(),
-            }),
-            // TODO: Same as above, if NSDictionary could support multiple types, this could be a boolean
-            NSString::from_str(match self.delivery_date {
-                Some((_, true)) => "yes",
-                _ => "",
-            }),
-            NSString::from_str(match self.sound {
-                Some(sound) if check_sound(sound) => sound,
-                _ => "_mute",
-            }),
-        ];
-        NSDictionary::from_keys_and_objects(keys, vals)
+        let mut builder = DictionaryBuilder::new();
+        builder
+            .string("mainButtonLabel", Some(main_button_label))
+            .strings("actions", actions)
+            .string("closeButtonLabel", self.close_button)
+            .string("appIcon", self.app_icon)
+            .string("contentImage", self.content_image)
+            .string("groupID", self.group_id)
+            .bool("response", is_response)
+            .number(
+                "deliveryDate",
+                self.delivery_date.map(|(delivery_date, _)| delivery_date),
+            )
+            .bool(
+                "synchronous",
+                matches!(self.delivery_date, Some((_, true))),
+            )
+            .string(
+                "sound",
+                Some(match self.sound {
+                    Some(sound) if check_sound(sound) => sound,
+                    _ => "_mute",
+                }),
+            )
+            .string("identifier", Some(&self.identifier_or_new()))
+            .number("badgeCount", self.badge.map(f64::from))
+            .string("sendButtonTitle", send_button_title)
+            .string("defaultText", default_text)
+            .string(
+                "interruptionLevel",
+                self.interruption_level.map(InterruptionLevel::as_str),
+            );
+        builder.finish()
     }
 }
 
@@ -237,36 +356,116 @@ pub enum NotificationResponse {
 
 impl NotificationResponse {
     /// Create a NotificationResponse from the given Objective C NSDictionary
-    pub(crate) fn from_dictionary(dictionary: Id<NSDictionary<NSString, NSString>>) -> Self {
-        let dictionary = dictionary.deref();
-
-        let activation_type =
-            match dictionary.object_for(NSString::from_str("activationType").deref()) {
-                Some(str) => Some(str.deref().as_str().to_owned()),
-                None => None,
-            };
+    pub(crate) fn from_dictionary(dictionary: Id<Object>) -> Self {
+        let activation_type = dictionary::string_for(&dictionary, "activationType");
+        let activation_value = || dictionary::string_for(&dictionary, "activationValue").unwrap_or_default();
 
         match activation_type.as_deref() {
-            Some("actionClicked") => NotificationResponse::ActionButton(
-                match dictionary.object_for(NSString::from_str("activationValue").deref()) {
-                    Some(str) => str.deref().as_str().to_owned(),
-                    None => String::from(""),
-                },
-            ),
-            Some("closeClicked") => NotificationResponse::CloseButton(
-                match dictionary.object_for(NSString::from_str("activationValue").deref()) {
-                    Some(str) => str.deref().as_str().to_owned(),
-                    None => String::from(""),
-                },
-            ),
-            Some("replied") => NotificationResponse::Reply(
-                match dictionary.object_for(NSString::from_str("activationValue").deref()) {
-                    Some(str) => str.deref().as_str().to_owned(),
-                    None => String::from(""),
-                },
-            ),
+            Some("actionClicked") => NotificationResponse::ActionButton(activation_value()),
+            Some("closeClicked") => NotificationResponse::CloseButton(activation_value()),
+            Some("replied") => NotificationResponse::Reply(activation_value()),
             Some("contentsClicked") => NotificationResponse::Click,
             _ => NotificationResponse::None,
         }
     }
 }
+
+/// Which Notification Center a [`NotificationHandle`] was delivered through,
+/// since `close`/`update` have to operate on that same center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NotificationSource {
+    /// Delivered via `send_notification`/`deliver` (legacy `NSUserNotificationCenter`).
+    Sync,
+    /// Delivered via `deliver_with_callback` (`UNUserNotificationCenter`).
+    Callback,
+}
+
+/// A handle to a delivered notification, returned by [`crate::deliver`] or
+/// [`crate::deliver_with_callback`].
+///
+/// Keeps enough state around to close the notification or re-deliver it with
+/// new options without it stacking on screen, which is useful for
+/// long-running or chat-style notifications.
+pub struct NotificationHandle {
+    identifier: String,
+    title: String,
+    subtitle: Option<String>,
+    message: String,
+    source: NotificationSource,
+}
+
+impl NotificationHandle {
+    pub(crate) fn new(
+        identifier: String,
+        title: String,
+        subtitle: Option<String>,
+        message: String,
+        source: NotificationSource,
+    ) -> Self {
+        NotificationHandle {
+            identifier,
+            title,
+            subtitle,
+            message,
+            source,
+        }
+    }
+
+    /// The identifier this notification was delivered under.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Remove the notification from the Notification Center.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use mac_notification_sys::*;
+    /// let handle = deliver("Title", None, "Message", &NotificationOptions::new()).unwrap();
+    /// handle.close();
+    /// ```
+    pub fn close(self) {
+        match self.source {
+            NotificationSource::Sync => crate::remove_delivered_notification(&self.identifier),
+            NotificationSource::Callback => {
+                crate::callback::remove_callback_notification(&self.identifier)
+            }
+        }
+    }
+
+    /// Re-deliver the notification with new options, replacing the banner
+    /// currently on screen rather than stacking a new one.
+    ///
+    /// Only the options change: the title, subtitle and message stay the
+    /// ones the notification was first delivered with.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use mac_notification_sys::*;
+    /// let mut handle = deliver("Title", None, "50%", &NotificationOptions::new()).unwrap();
+    /// handle.update(&NotificationOptions::new()).unwrap();
+    /// ```
+    pub fn update(&mut self, options: &NotificationOptions) -> Result<(), crate::Error> {
+        let mut options = options.clone();
+        options.identifier(&self.identifier);
+        match self.source {
+            NotificationSource::Sync => {
+                crate::send_notification(&self.title, self.subtitle.as_deref(), &self.message, &options)?;
+            }
+            NotificationSource::Callback => {
+                // Re-posts under the same identifier; if the original
+                // callback hasn't fired yet it still applies to the
+                // replacement banner, since it's keyed by identifier.
+                crate::callback::redeliver_callback(
+                    &self.title,
+                    self.subtitle.as_deref(),
+                    &self.message,
+                    &options,
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+//! Low-level helpers for building and reading the `NSDictionary` passed
+//! across the Objective-C bridge.
+//!
+//! `objc_foundation`'s `NSDictionary<K, V>` only supports a single
+//! Objective-C value type, which used to force every option through
+//! `NSString` — arrays became comma-joined strings and booleans became
+//! `"yes"`/`""` sentinels, both lossy. These helpers build a plain
+//! `NSDictionary<NSString, NSObject>` instead, so `actions` can be a real
+//! `NSArray`, `synchronous`/`response` can be genuine `NSNumber` booleans,
+//! and `deliveryDate` can be an `NSNumber` double.
+
+use objc::runtime::{Class, Object, BOOL, NO, YES};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_foundation::{INSString, NSString};
+use objc_id::Id;
+
+/// An `NSMutableDictionary<NSString, NSObject>` under construction.
+pub(crate) struct DictionaryBuilder {
+    dictionary: Id<Object>,
+}
+
+fn ns_string(value: &str) -> Id<Object> {
+    unsafe { Id::from_retained_ptr(NSString::from_str(value).into_ptr() as *mut Object) }
+}
+
+impl DictionaryBuilder {
+    pub(crate) fn new() -> Self {
+        unsafe {
+            let class: &Class = class!(NSMutableDictionary);
+            let dictionary: *mut Object = msg_send![class, dictionaryWithCapacity: 16usize];
+            DictionaryBuilder {
+                dictionary: Id::from_ptr(dictionary),
+            }
+        }
+    }
+
+    fn insert(&mut self, key: &str, value: Id<Object>) -> &mut Self {
+        unsafe {
+            let key = ns_string(key);
+            let _: () = msg_send![self.dictionary, setObject: value forKey: key];
+        }
+        self
+    }
+
+    /// Insert a string value, or skip the key entirely when `None`.
+    pub(crate) fn string(&mut self, key: &str, value: Option<&str>) -> &mut Self {
+        match value {
+            Some(value) => self.insert(key, ns_string(value)),
+            None => self,
+        }
+    }
+
+    /// Insert an `NSArray` of strings.
+    pub(crate) fn strings(&mut self, key: &str, values: &[&str]) -> &mut Self {
+        unsafe {
+            let class: &Class = class!(NSMutableArray);
+            let array: *mut Object = msg_send![class, arrayWithCapacity: values.len()];
+            for value in values {
+                let _: () = msg_send![array, addObject: ns_string(value)];
+            }
+            self.insert(key, Id::from_ptr(array))
+        }
+    }
+
+    /// Insert a boolean as a genuine `NSNumber`, rather than a string sentinel.
+    pub(crate) fn bool(&mut self, key: &str, value: bool) -> &mut Self {
+        unsafe {
+            let value: BOOL = if value { YES } else { NO };
+            let class: &Class = class!(NSNumber);
+            let number: *mut Object = msg_send![class, numberWithBool: value];
+            self.insert(key, Id::from_ptr(number))
+        }
+    }
+
+    /// Insert a double as an `NSNumber`, or skip the key when `None`.
+    pub(crate) fn number(&mut self, key: &str, value: Option<f64>) -> &mut Self {
+        match value {
+            Some(value) => unsafe {
+                let class: &Class = class!(NSNumber);
+                let number: *mut Object = msg_send![class, numberWithDouble: value];
+                self.insert(key, Id::from_ptr(number))
+            },
+            None => self,
+        }
+    }
+
+    pub(crate) fn finish(self) -> Id<Object> {
+        self.dictionary
+    }
+}
+
+/// Read a string value out of a response `NSDictionary<NSString, NSObject>`.
+pub(crate) fn string_for(dictionary: &Object, key: &str) -> Option<String> {
+    unsafe {
+        let key = ns_string(key);
+        let value: *mut Object = msg_send![dictionary, objectForKey: key];
+        if value.is_null() {
+            return None;
+        }
+        let value = value as *mut NSString;
+        Some((*value).as_str().to_owned())
+    }
+}
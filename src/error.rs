@@ -0,0 +1,69 @@
+//! Error types for mac-notification-sys.
+
+use std::fmt;
+
+/// Errors that can occur while delivering or managing a notification.
+#[derive(Debug)]
+pub enum Error {
+    /// Something went wrong while resolving the calling application.
+    Application(ApplicationError),
+    /// Something went wrong while delivering a notification.
+    Notification(NotificationError),
+}
+
+/// Errors that can occur when resolving the calling application.
+#[derive(Debug)]
+pub enum ApplicationError {
+    /// The application bundle identifier has already been set.
+    AlreadySet,
+}
+
+/// Errors that can occur while delivering a notification.
+#[derive(Debug)]
+pub enum NotificationError {
+    /// The Notification Center refused to deliver the notification.
+    Unauthorized,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Application(err) => write!(f, "{}", err),
+            Error::Notification(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl fmt::Display for ApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApplicationError::AlreadySet => write!(f, "the application has already been set"),
+        }
+    }
+}
+
+impl fmt::Display for NotificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationError::Unauthorized => {
+                write!(f, "notifications are not authorized for this application")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+impl std::error::Error for ApplicationError {}
+impl std::error::Error for NotificationError {}
+
+impl From<ApplicationError> for Error {
+    fn from(err: ApplicationError) -> Self {
+        Error::Application(err)
+    }
+}
+
+impl From<NotificationError> for Error {
+    fn from(err: NotificationError) -> Self {
+        Error::Notification(err)
+    }
+}
@@ -0,0 +1,105 @@
+//! Bindings to deliver native notifications on macOS.
+
+mod authorization;
+mod badge;
+mod callback;
+mod data;
+mod dictionary;
+mod error;
+mod utilities;
+
+pub use crate::authorization::{get_authorization_status, request_authorization, AuthorizationStatus};
+pub use crate::badge::{get_badge, set_badge};
+pub use crate::callback::deliver_with_callback;
+pub use crate::data::{
+    InterruptionLevel, MainButton, NotificationHandle, NotificationOptions, NotificationResponse,
+};
+use crate::data::NotificationSource;
+pub use crate::error::{ApplicationError, Error, NotificationError};
+pub use crate::utilities::{check_sound, get_application, set_application};
+
+use objc::runtime::Object;
+use objc_foundation::{INSString, NSString};
+use objc_id::Id;
+
+#[link(name = "notify")]
+extern "C" {
+    fn sendNotification(
+        title: *mut NSString,
+        subtitle: *mut NSString,
+        message: *mut NSString,
+        options: *mut Object,
+    ) -> *mut Object;
+
+    fn removeDeliveredNotification(identifier: *mut NSString);
+}
+
+pub(crate) fn remove_delivered_notification(identifier: &str) {
+    unsafe { removeDeliveredNotification(Id::autorelease(NSString::from_str(identifier))) }
+}
+
+pub(crate) fn opt_nsstring(value: Option<&str>) -> *mut NSString {
+    match value {
+        Some(value) => Id::autorelease(NSString::from_str(value)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Deliver a notification with the given `title`, `subtitle` and `message`.
+///
+/// Use [`NotificationOptions`] to further customize the notification, e.g. to
+/// attach actions, an icon, or schedule it for later delivery.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use mac_notification_sys::*;
+/// let _ = send_notification("Title", None, "Message", &NotificationOptions::new());
+/// ```
+pub fn send_notification(
+    title: &str,
+    subtitle: Option<&str>,
+    message: &str,
+    options: &NotificationOptions,
+) -> Result<NotificationResponse, Error> {
+    let dictionary = options.to_dictionary();
+    let response = unsafe {
+        let response = sendNotification(
+            Id::autorelease(NSString::from_str(title)),
+            opt_nsstring(subtitle),
+            Id::autorelease(NSString::from_str(message)),
+            Id::autorelease(dictionary),
+        );
+        Id::from_retained_ptr(response)
+    };
+    Ok(NotificationResponse::from_dictionary(response))
+}
+
+/// Deliver a notification and return a [`NotificationHandle`] that can later
+/// be used to close or replace it in place.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use mac_notification_sys::*;
+/// let handle = deliver("Title", None, "Message", &NotificationOptions::new()).unwrap();
+/// handle.close();
+/// ```
+pub fn deliver(
+    title: &str,
+    subtitle: Option<&str>,
+    message: &str,
+    options: &NotificationOptions,
+) -> Result<NotificationHandle, Error> {
+    let identifier = options.identifier_or_new();
+    let mut options = options.clone();
+    options.identifier(&identifier);
+    send_notification(title, subtitle, message, &options)?;
+    Ok(NotificationHandle::new(
+        identifier,
+        title.to_owned(),
+        subtitle.map(str::to_owned),
+        message.to_owned(),
+        NotificationSource::Sync,
+    ))
+}
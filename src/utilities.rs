@@ -0,0 +1,44 @@
+//! Helper functions for mac-notification-sys.
+
+use crate::error::{ApplicationError, Error};
+use objc_foundation::{INSString, NSString};
+use objc_id::Id;
+use std::sync::Mutex;
+
+#[link(name = "notify")]
+extern "C" {
+    fn setApplication(bundle_identifier: *mut NSString);
+}
+
+lazy_static::lazy_static! {
+    static ref APPLICATION: Mutex<Option<String>> = Mutex::new(None);
+}
+
+const SOUNDS: &[&str] = &[
+    "Basso", "Blow", "Bottle", "Frog", "Funk", "Glass", "Hero", "Morse", "Ping", "Pop", "Purr",
+    "Sosumi", "Submarine", "Tink",
+];
+
+/// Returns whether `sound` names one of the system sounds macOS ships with.
+pub fn check_sound(sound: &str) -> bool {
+    SOUNDS.contains(&sound)
+}
+
+/// Explicitly set the bundle identifier notifications should be delivered under.
+///
+/// This is required for binaries that aren't bundled inside a `.app`, since
+/// `NSUserNotificationCenter` refuses to post on their behalf otherwise.
+pub fn set_application(bundle_ident: &str) -> Result<(), Error> {
+    let mut application = APPLICATION.lock().unwrap();
+    if application.is_some() {
+        return Err(ApplicationError::AlreadySet.into());
+    }
+    unsafe { setApplication(Id::autorelease(NSString::from_str(bundle_ident))) };
+    *application = Some(bundle_ident.to_owned());
+    Ok(())
+}
+
+/// Get the bundle identifier currently used to deliver notifications, if any.
+pub fn get_application() -> Option<String> {
+    APPLICATION.lock().unwrap().clone()
+}